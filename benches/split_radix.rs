@@ -0,0 +1,39 @@
+#![feature(test)]
+#![feature(generic_const_exprs)]
+
+extern crate dft;
+extern crate num_complex;
+extern crate test;
+
+use core::mem::MaybeUninit;
+use dft::{c64, transform, transform_split, Operation, Plan, SplitPlan};
+use test::Bencher;
+
+const N: usize = 1024;
+
+fn data() -> [c64; N] {
+    core::array::from_fn(|i| c64::new(i as f64, (N - i) as f64))
+}
+
+#[bench]
+fn radix2(bencher: &mut Bencher) {
+    let mut factors = MaybeUninit::uninit();
+    let plan = Plan::new(Operation::Forward, &mut factors);
+    bencher.iter(|| {
+        let mut samples = data();
+        transform(&mut samples, &plan);
+        samples
+    });
+}
+
+#[bench]
+fn split_radix(bencher: &mut Bencher) {
+    let mut twiddles = MaybeUninit::uninit();
+    let mut triples = MaybeUninit::uninit();
+    let plan = SplitPlan::new(Operation::Forward, &mut twiddles, &mut triples);
+    bencher.iter(|| {
+        let mut samples = data();
+        transform_split(&mut samples, &plan);
+        samples
+    });
+}
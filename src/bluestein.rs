@@ -0,0 +1,92 @@
+// Chirp-z (Bluestein) transform. Reduces a length-`N` DFT to a convolution
+// that is carried out with the radix-2 engine in `complex.rs`, so that awkward
+// lengths — in particular large primes — reuse the fast power-of-two path.
+
+use core::mem::MaybeUninit;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+use {Operation, Plan, Transform};
+
+/// The padded power-of-two length used by Bluestein for a length-`N` input.
+///
+/// It is the smallest power of two not below `2·N − 1`, which is long enough to
+/// hold the linear convolution of two length-`N` sequences.
+pub const fn bluestein_len(n: usize) -> usize {
+    let target = 2 * n - 1;
+    let mut m = 1;
+    while m < target {
+        m <<= 1;
+    }
+    m
+}
+
+/// Perform the transform of `data` using the Bluestein algorithm.
+///
+/// Any length is admissible; the algorithm is most useful when `N` has large
+/// prime factors, for which mixed-radix degrades to a quadratic column DFT. The
+/// chirp phases `W^{±n²/2}` with `W = exp(−2πi/N)` are formed on the fly, the
+/// convolution is evaluated in the padded length [`bluestein_len`], and the
+/// result is written back in place following the same scaling convention as
+/// [`Transform::transform`].
+pub fn bluestein<T, const N: usize>(data: &mut [Complex<T>; N], operation: Operation)
+where
+    T: Float + FloatConst,
+    [(); bluestein_len(N)]: Sized,
+{
+    let m = bluestein_len(N);
+
+    let one = T::one();
+    let sign = if let Operation::Forward = operation {
+        -one
+    } else {
+        one
+    };
+    let pi = T::PI();
+    let n = T::from(N).unwrap();
+
+    // chirp[k] = exp(sign · iπ k² / N), reduced via k² mod 2N for accuracy.
+    let chirp = |k: usize| -> Complex<T> {
+        let r = T::from((k * k) % (2 * N)).unwrap();
+        let angle = sign * pi * r / n;
+        Complex::new(angle.cos(), angle.sin())
+    };
+
+    let mut a = core::array::from_fn::<_, { bluestein_len(N) }, _>(|_| {
+        Complex::new(T::zero(), T::zero())
+    });
+    let mut b = core::array::from_fn::<_, { bluestein_len(N) }, _>(|_| {
+        Complex::new(T::zero(), T::zero())
+    });
+
+    for k in 0..N {
+        a[k] = data[k] * chirp(k);
+    }
+    b[0] = Complex::new(one, T::zero());
+    for k in 1..N {
+        let value = chirp(k).conj();
+        b[k] = value;
+        b[m - k] = value;
+    }
+
+    let mut factors = MaybeUninit::uninit();
+    let forward = Plan::<T, { bluestein_len(N) }>::new(Operation::Forward, &mut factors);
+    a.transform(&forward);
+    b.transform(&forward);
+    for k in 0..m {
+        a[k] = a[k] * b[k];
+    }
+    let mut factors = MaybeUninit::uninit();
+    let inverse = Plan::<T, { bluestein_len(N) }>::new(Operation::Inverse, &mut factors);
+    a.transform(&inverse);
+
+    for k in 0..N {
+        data[k] = a[k] * chirp(k);
+    }
+    if let Operation::Inverse = operation {
+        let scale = n.recip();
+        for value in data.iter_mut() {
+            *value = value.scale(scale);
+        }
+    }
+}
@@ -11,14 +11,86 @@ where
     T: Float,
 {
     fn transform(&mut self, plan: &Plan<T, N>) {
-        rearrange(self);
-        calculate(self, &plan.factors);
+        if N.is_power_of_two() {
+            rearrange(self);
+            calculate(self, plan.factors);
+        } else {
+            let mut scratch =
+                core::array::from_fn::<_, N, _>(|_| Complex::new(T::zero(), T::zero()));
+            mixed_radix(self, &mut scratch, plan.factors, N);
+        }
         if let Operation::Inverse = plan.operation {
             scale(self, N);
         }
     }
 }
 
+/// Mixed-radix Cooley–Tukey transform for arbitrary lengths.
+///
+/// The length is factored as `n = n1 * n2`, with `n1` the smallest prime
+/// factor, and the input index is read as `i·n2 + j` (`i` in `0..n1`, `j` in
+/// `0..n2`). Following the decimation, the columns (over `i`, length `n1`) are
+/// transformed by a direct DFT, each result is multiplied by the twiddle
+/// `W_n^{j·k1}`, the rows (over `j`, length `n2`) are transformed recursively,
+/// and the output index is read transposed as `k1 + k2·n1`. `twiddles` is the
+/// length-`full_n` table `W_{full_n}^k`, from which every sub-twiddle is
+/// obtained by index scaling.
+fn mixed_radix<T>(
+    data: &mut [Complex<T>],
+    scratch: &mut [Complex<T>],
+    twiddles: &[Complex<T>],
+    full_n: usize,
+)
+where
+    T: Float,
+{
+    let n = data.len();
+    if n < 2 {
+        return;
+    }
+    let n1 = smallest_factor(n);
+    let n2 = n / n1;
+    // Transform the columns of length `n1` directly and apply the twiddle
+    // `W_n^{j·k1}`, storing the result row-major as `k1·n2 + j`.
+    let col_scale = full_n / n1;
+    let row_scale = full_n / n;
+    for j in 0..n2 {
+        for k1 in 0..n1 {
+            let mut sum = Complex::new(T::zero(), T::zero());
+            for i in 0..n1 {
+                let index = (i * k1 * col_scale) % full_n;
+                sum = sum + data[i * n2 + j] * twiddles[index];
+            }
+            let twiddle = (j * k1 * row_scale) % full_n;
+            scratch[k1 * n2 + j] = sum * twiddles[twiddle];
+        }
+    }
+    data.copy_from_slice(&scratch[..n]);
+    // Transform the rows of length `n2` recursively.
+    for k1 in 0..n1 {
+        mixed_radix(&mut data[k1 * n2..k1 * n2 + n2], scratch, twiddles, full_n);
+    }
+    // Read the output transposed: X[k1 + k2·n1] = data[k1·n2 + k2].
+    for k1 in 0..n1 {
+        for k2 in 0..n2 {
+            scratch[k1 + k2 * n1] = data[k1 * n2 + k2];
+        }
+    }
+    data.copy_from_slice(&scratch[..n]);
+}
+
+#[inline]
+fn smallest_factor(n: usize) -> usize {
+    let mut d = 2;
+    while d * d <= n {
+        if n % d == 0 {
+            return d;
+        }
+        d += 1;
+    }
+    n
+}
+
 #[inline(always)]
 fn calculate<T, const N: usize>(data: &mut [Complex<T>; N], factors: &[Complex<T>; N])
 where
@@ -0,0 +1,119 @@
+// High-level convolution helpers layered on the forward/inverse engine. They
+// hide the zero-padding and the pointwise-product step so that multiplying two
+// sequences (equivalently, two polynomials) is a single call.
+
+use core::mem::MaybeUninit;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+use {Operation, Plan, Transform};
+
+/// The length of the linear convolution of sequences of length `a` and `b`.
+pub const fn convolution_len(a: usize, b: usize) -> usize {
+    a + b - 1
+}
+
+/// The padded power-of-two length used to evaluate the convolution.
+pub const fn transform_len(a: usize, b: usize) -> usize {
+    let target = a + b - 1;
+    let mut m = 1;
+    while m < target {
+        m <<= 1;
+    }
+    m
+}
+
+/// Linearly convolve two complex sequences.
+///
+/// Both inputs are zero-padded to the smallest power of two not below
+/// `LA + LB − 1`, transformed, multiplied element-wise, and transformed back;
+/// the first `LA + LB − 1` samples — the full linear convolution — are
+/// returned. This is the acyclic product; for the wrap-around product of two
+/// equal-length signals see [`circular_convolve`].
+pub fn convolve<T, const LA: usize, const LB: usize>(
+    a: &[Complex<T>; LA],
+    b: &[Complex<T>; LB],
+) -> [Complex<T>; convolution_len(LA, LB)]
+where
+    T: Float + FloatConst,
+    [(); transform_len(LA, LB)]: Sized,
+    [(); convolution_len(LA, LB)]: Sized,
+{
+    let m = transform_len(LA, LB);
+    let mut fa = core::array::from_fn::<_, { transform_len(LA, LB) }, _>(|i| {
+        if i < LA {
+            a[i]
+        } else {
+            Complex::new(T::zero(), T::zero())
+        }
+    });
+    let mut fb = core::array::from_fn::<_, { transform_len(LA, LB) }, _>(|i| {
+        if i < LB {
+            b[i]
+        } else {
+            Complex::new(T::zero(), T::zero())
+        }
+    });
+
+    let mut factors = MaybeUninit::uninit();
+    let forward = Plan::<T, { transform_len(LA, LB) }>::new(Operation::Forward, &mut factors);
+    fa.transform(&forward);
+    fb.transform(&forward);
+    for i in 0..m {
+        fa[i] = fa[i] * fb[i];
+    }
+    let mut factors = MaybeUninit::uninit();
+    let inverse = Plan::<T, { transform_len(LA, LB) }>::new(Operation::Inverse, &mut factors);
+    fa.transform(&inverse);
+
+    core::array::from_fn::<_, { convolution_len(LA, LB) }, _>(|i| fa[i])
+}
+
+/// Linearly convolve two real sequences.
+///
+/// The inputs are lifted into the complex plane, convolved, and the real parts
+/// returned. (When spectral reuse matters the same result can be obtained by
+/// transforming the packed real signals and [`unpack`](crate::unpack)ing before
+/// the pointwise product; this direct form trades a little memory for
+/// simplicity.)
+pub fn convolve_real<T, const LA: usize, const LB: usize>(
+    a: &[T; LA],
+    b: &[T; LB],
+) -> [T; convolution_len(LA, LB)]
+where
+    T: Float + FloatConst,
+    [(); transform_len(LA, LB)]: Sized,
+    [(); convolution_len(LA, LB)]: Sized,
+{
+    let ca = core::array::from_fn::<_, LA, _>(|i| Complex::new(a[i], T::zero()));
+    let cb = core::array::from_fn::<_, LB, _>(|i| Complex::new(b[i], T::zero()));
+    let product = convolve(&ca, &cb);
+    core::array::from_fn::<_, { convolution_len(LA, LB) }, _>(|i| product[i].re)
+}
+
+/// Circularly convolve two complex sequences of equal power-of-two length.
+///
+/// No padding is performed, so the result wraps around modulo `N`; use
+/// [`convolve`] for the linear product.
+pub fn circular_convolve<T, const N: usize>(
+    a: &[Complex<T>; N],
+    b: &[Complex<T>; N],
+) -> [Complex<T>; N]
+where
+    T: Float + FloatConst,
+{
+    assert!(N.is_power_of_two());
+    let mut fa = *a;
+    let mut fb = *b;
+    let mut factors = MaybeUninit::uninit();
+    let forward = Plan::<T, N>::new(Operation::Forward, &mut factors);
+    fa.transform(&forward);
+    fb.transform(&forward);
+    for i in 0..N {
+        fa[i] = fa[i] * fb[i];
+    }
+    let mut factors = MaybeUninit::uninit();
+    let inverse = Plan::<T, N>::new(Operation::Inverse, &mut factors);
+    fa.transform(&inverse);
+    fa
+}
@@ -53,10 +53,18 @@ pub type c32 = Complex<f32>;
 #[allow(non_camel_case_types)]
 pub type c64 = Complex<f64>;
 
+mod bluestein;
 mod complex;
+mod convolution;
 mod real;
+mod ring;
+mod split;
 
+pub use bluestein::{bluestein, bluestein_len};
+pub use convolution::{circular_convolve, convolve, convolve_real};
 pub use real::unpack;
+pub use ring::{transform as transform_in_ring, CyclotomicRing, Fp};
+pub use split::{transform_split, SplitPlan};
 
 /// A transform operation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -88,9 +96,15 @@ where
 {
     /// Create a plan for a specific operation and specific number of points.
     ///
-    /// The number of points should be a power of two.
+    /// The number of points may be arbitrary. Powers of two take the radix-2
+    /// path, while other lengths are factored and handled by the mixed-radix
+    /// Cooley–Tukey engine; in the latter case the auxiliary array holds the
+    /// length-`N` twiddle table `W_N^k`.
+    ///
+    /// Arbitrary lengths apply to complex data only. The transform of real data
+    /// gives correct results only when `N` is a power of two; other lengths are
+    /// not rejected.
     pub fn new(operation: Operation, factors: &'a mut MaybeUninit<[Complex<T>; N]>) -> Self {
-        assert!(N.is_power_of_two());
         let one = T::one();
         let two = one + one;
         let sign = if let Operation::Forward = operation {
@@ -98,23 +112,32 @@ where
         } else {
             one
         };
-        let mut i = 0;
-        let mut step = 1;
-        while step < N {
-            let (multiplier, mut factor) = {
-                let theta = T::PI() / T::from(step).unwrap();
-                let sine = (theta / two).sin();
-                (
-                    Complex::new(-two * sine * sine, sign * theta.sin()),
-                    Complex::one(),
-                )
-            };
-            for _ in 0..step {
-                unsafe { factors.as_mut_ptr().cast::<Complex<T>>().add(i).write(factor) }
-                i += 1;
-                factor = multiplier * factor + factor;
+        if N.is_power_of_two() {
+            let mut i = 0;
+            let mut step = 1;
+            while step < N {
+                let (multiplier, mut factor) = {
+                    let theta = T::PI() / T::from(step).unwrap();
+                    let sine = (theta / two).sin();
+                    (
+                        Complex::new(-two * sine * sine, sign * theta.sin()),
+                        Complex::one(),
+                    )
+                };
+                for _ in 0..step {
+                    unsafe { factors.as_mut_ptr().cast::<Complex<T>>().add(i).write(factor) }
+                    i += 1;
+                    factor = multiplier * factor + factor;
+                }
+                step <<= 1;
+            }
+        } else {
+            let base = factors.as_mut_ptr().cast::<Complex<T>>();
+            let theta = sign * two * T::PI() / T::from(N).unwrap();
+            for k in 0..N {
+                let angle = theta * T::from(k).unwrap();
+                unsafe { base.add(k).write(Complex::new(angle.cos(), angle.sin())) }
             }
-            step <<= 1;
         }
         Plan {
             factors: unsafe { factors.assume_init_ref() },
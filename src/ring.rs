@@ -0,0 +1,204 @@
+// A radix-2 engine generalized over the element type. The butterfly and the
+// twiddle recurrence are identical for the complex transform and the
+// number-theoretic transform (NTT); only the scalar ring and its root of unity
+// differ. `CyclotomicRing` captures exactly that interface, letting the same
+// decimation-in-time loop run over `Complex<T>` or over a modular field
+// `Fp<P>` for exact, rounding-free convolutions.
+
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+use Operation;
+
+/// A ring that carries a primitive `N`-th root of unity.
+///
+/// The implementations supplied by the crate are [`Complex<T>`], reproducing
+/// the floating-point transform, and [`Fp`], giving the NTT over `ℤ/pℤ`.
+pub trait CyclotomicRing<const N: usize>: Copy {
+    /// The additive identity.
+    fn zero() -> Self;
+    /// The multiplicative identity.
+    fn identity() -> Self;
+    /// Add two elements.
+    fn add(self, other: Self) -> Self;
+    /// Subtract two elements.
+    fn sub(self, other: Self) -> Self;
+    /// Multiply two elements.
+    fn mul(self, other: Self) -> Self;
+    /// A primitive `N`-th root of unity, forward or inverse as requested.
+    fn root(operation: Operation) -> Self;
+    /// The multiplicative inverse of `N`, used to normalize the inverse
+    /// transform.
+    fn inverse_of_length() -> Self;
+}
+
+/// Transform `data` in place over an arbitrary [`CyclotomicRing`].
+///
+/// The length must be a power of two. The loop is the same decimation-in-time
+/// radix-2 Cooley–Tukey butterfly used by the complex engine; the inverse
+/// operation multiplies every element by `N^{-1}` instead of scaling by a
+/// reciprocal.
+pub fn transform<R, const N: usize>(data: &mut [R; N], operation: Operation)
+where
+    R: CyclotomicRing<N>,
+{
+    assert!(N.is_power_of_two());
+    rearrange(data);
+    let omega = R::root(operation);
+    let mut twiddles = core::array::from_fn::<_, N, _>(|_| R::identity());
+    for k in 1..N {
+        twiddles[k] = twiddles[k - 1].mul(omega);
+    }
+    let mut len = 2;
+    while len <= N {
+        let step = N / len;
+        let half = len >> 1;
+        let mut start = 0;
+        while start < N {
+            for i in 0..half {
+                let w = twiddles[i * step];
+                let u = data[start + i];
+                let v = data[start + i + half].mul(w);
+                data[start + i] = u.add(v);
+                data[start + i + half] = u.sub(v);
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+    if let Operation::Inverse = operation {
+        let inverse = R::inverse_of_length();
+        for value in data.iter_mut() {
+            *value = value.mul(inverse);
+        }
+    }
+}
+
+#[inline(always)]
+fn rearrange<R, const N: usize>(data: &mut [R; N]) {
+    let mut j = 0;
+    for i in 0..N {
+        if j > i {
+            data.swap(i, j);
+        }
+        let mut mask = N >> 1;
+        while j & mask != 0 {
+            j &= !mask;
+            mask >>= 1;
+        }
+        j |= mask;
+    }
+}
+
+impl<T, const N: usize> CyclotomicRing<N> for Complex<T>
+where
+    T: Float + FloatConst,
+{
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn identity() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    fn mul(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn root(operation: Operation) -> Self {
+        let one = T::one();
+        let sign = if let Operation::Forward = operation {
+            -one
+        } else {
+            one
+        };
+        let angle = sign * (one + one) * T::PI() / T::from(N).unwrap();
+        Complex::new(angle.cos(), angle.sin())
+    }
+
+    fn inverse_of_length() -> Self {
+        Complex::new(T::from(N).unwrap().recip(), T::zero())
+    }
+}
+
+/// An element of the prime field `ℤ/Pℤ`.
+///
+/// `P` is expected to be an NTT-friendly prime `c·2^k + 1` with `3` as a
+/// generator (e.g. `998244353 = 119·2^23 + 1`), and `N` must divide `P − 1`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fp<const P: u64>(pub u64);
+
+impl<const P: u64> Fp<P> {
+    /// The generator used to derive roots of unity.
+    const GENERATOR: u64 = 3;
+
+    /// Reduce a value into the field.
+    pub const fn new(value: u64) -> Self {
+        Fp(value % P)
+    }
+
+    #[inline]
+    fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self.0 as u128;
+        let mut result: u128 = 1;
+        let modulus = P as u128;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exponent >>= 1;
+        }
+        Fp(result as u64)
+    }
+
+    #[inline]
+    fn inverse(self) -> Self {
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64, const N: usize> CyclotomicRing<N> for Fp<P> {
+    fn zero() -> Self {
+        Fp(0)
+    }
+
+    fn identity() -> Self {
+        Fp(1)
+    }
+
+    fn add(self, other: Self) -> Self {
+        let sum = self.0 as u128 + other.0 as u128;
+        Fp((sum % P as u128) as u64)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Fp((self.0 + P - other.0) % P)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Fp((self.0 as u128 * other.0 as u128 % P as u128) as u64)
+    }
+
+    fn root(operation: Operation) -> Self {
+        let generator = Fp::<P>(Self::GENERATOR);
+        let omega = generator.pow((P - 1) / N as u64);
+        match operation {
+            Operation::Forward => omega,
+            Operation::Backward | Operation::Inverse => omega.inverse(),
+        }
+    }
+
+    fn inverse_of_length() -> Self {
+        Fp::<P>(N as u64 % P).inverse()
+    }
+}
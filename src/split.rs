@@ -0,0 +1,185 @@
+// Split-radix (radix-2/4) kernel. Split-radix computes a power-of-two transform
+// with roughly a third fewer multiplications than plain radix-2 by handling the
+// even-indexed outputs with a length-`N/2` sub-transform and the odd-indexed
+// outputs with two length-`N/4` sub-transforms recombined through the twiddle
+// pairs `W^k` and `W^{3k}`. For `N < 8` or lengths that are not a multiple of
+// four the recombination degenerates to a direct DFT.
+
+use core::mem::MaybeUninit;
+use num_complex::Complex;
+use num_traits::{Float, FloatConst};
+
+use Operation;
+
+/// A precomputed plan for the split-radix kernel.
+///
+/// Like [`Plan`](crate::Plan) it caches the twiddle factors so they are formed
+/// once and reused across transforms; split-radix needs both the `W^k` table
+/// and the `W^{3k}` table that the odd half recombines with.
+#[derive(Clone, Debug)]
+pub struct SplitPlan<'a, T, const N: usize> {
+    twiddles: &'a [Complex<T>; N],
+    triples: &'a [Complex<T>; N],
+    operation: Operation,
+}
+
+impl<'a, T, const N: usize> SplitPlan<'a, T, N>
+where
+    T: Float + FloatConst,
+{
+    /// Create a split-radix plan, precomputing the `W^k` and `W^{3k}` tables.
+    pub fn new(
+        operation: Operation,
+        twiddles: &'a mut MaybeUninit<[Complex<T>; N]>,
+        triples: &'a mut MaybeUninit<[Complex<T>; N]>,
+    ) -> Self {
+        let one = T::one();
+        let two = one + one;
+        let sign = if let Operation::Forward = operation {
+            -one
+        } else {
+            one
+        };
+        let theta = sign * two * T::PI() / T::from(N).unwrap();
+        let base = twiddles.as_mut_ptr().cast::<Complex<T>>();
+        for k in 0..N {
+            let angle = theta * T::from(k).unwrap();
+            unsafe { base.add(k).write(Complex::new(angle.cos(), angle.sin())) }
+        }
+        let twiddles = unsafe { twiddles.assume_init_ref() };
+        let base = triples.as_mut_ptr().cast::<Complex<T>>();
+        for k in 0..N {
+            unsafe { base.add(k).write(twiddles[(3 * k) % N]) }
+        }
+        SplitPlan {
+            twiddles,
+            triples: unsafe { triples.assume_init_ref() },
+            operation,
+        }
+    }
+}
+
+/// Transform `data` in place with the split-radix kernel.
+///
+/// The interface mirrors [`Transform::transform`](crate::Transform::transform):
+/// the plan selects the direction, and the inverse is normalized by `1/N`.
+pub fn transform_split<T, const N: usize>(data: &mut [Complex<T>; N], plan: &SplitPlan<T, N>)
+where
+    T: Float,
+{
+    let mut scratch = core::array::from_fn::<_, N, _>(|_| Complex::new(T::zero(), T::zero()));
+    if N < 8 || N % 4 != 0 {
+        direct_dft(data, &mut scratch, plan.twiddles, N);
+    } else {
+        split_radix(data, &mut scratch, plan.twiddles, plan.triples, N);
+    }
+    if let Operation::Inverse = plan.operation {
+        let factor = T::from(N).unwrap().recip();
+        for value in data.iter_mut() {
+            *value = value.scale(factor);
+        }
+    }
+}
+
+/// Recursive split-radix butterfly over a length-`n` slice.
+///
+/// `twiddles` and `triples` are the length-`full_n` tables `W_{full_n}^k` and
+/// `W_{full_n}^{3k}`; a sub-twiddle at the current length is obtained by scaling
+/// the index by `full_n / n`. The `k = 0` twiddles are unity and the
+/// `k = n/8` twiddles are `(1 ± i)/√2` and `(−1 ± i)/√2`, so both are handled with additions and
+/// a single scalar scale instead of general complex multiplications.
+fn split_radix<T>(
+    data: &mut [Complex<T>],
+    scratch: &mut [Complex<T>],
+    twiddles: &[Complex<T>],
+    triples: &[Complex<T>],
+    full_n: usize,
+)
+where
+    T: Float,
+{
+    let n = data.len();
+    if n < 8 || n % 4 != 0 {
+        direct_dft(data, scratch, twiddles, full_n);
+        return;
+    }
+
+    let half = n / 2;
+    let quarter = n / 4;
+
+    // Decimate into the even subsequence and the two odd subsequences, laid out
+    // contiguously so the recursion can work in place.
+    for t in 0..half {
+        scratch[t] = data[2 * t];
+    }
+    for t in 0..quarter {
+        scratch[half + t] = data[4 * t + 1];
+        scratch[half + quarter + t] = data[4 * t + 3];
+    }
+    data[..n].copy_from_slice(&scratch[..n]);
+
+    split_radix(&mut data[..half], scratch, twiddles, triples, full_n);
+    split_radix(&mut data[half..half + quarter], scratch, twiddles, triples, full_n);
+    split_radix(&mut data[half + quarter..n], scratch, twiddles, triples, full_n);
+
+    let scale = full_n / n;
+    // W_n^{n/4} = ∓i; its imaginary part carries the transform's sign.
+    let rot = twiddles[(quarter * scale) % full_n];
+    let sign = rot.im;
+    let inv_sqrt2 = (T::one() + T::one()).sqrt().recip();
+    let eighth = if n % 8 == 0 { n / 8 } else { usize::MAX };
+    for k in 0..quarter {
+        let even0 = data[k];
+        let even1 = data[k + quarter];
+        let lower = data[half + k];
+        let upper = data[half + quarter + k];
+        let (odd1, odd2) = if k == 0 {
+            // W^0 = W^0 = 1.
+            (lower, upper)
+        } else if k == eighth {
+            // W^k = (1 + sign·i)/√2, W^{3k} = (−1 + sign·i)/√2.
+            let o1 = Complex::new(
+                (lower.re - sign * lower.im) * inv_sqrt2,
+                (lower.im + sign * lower.re) * inv_sqrt2,
+            );
+            let o2 = Complex::new(
+                (-upper.re - sign * upper.im) * inv_sqrt2,
+                (sign * upper.re - upper.im) * inv_sqrt2,
+            );
+            (o1, o2)
+        } else {
+            let index = (k * scale) % full_n;
+            (twiddles[index] * lower, triples[index] * upper)
+        };
+        let sum = odd1 + odd2;
+        let diff = odd1 - odd2;
+        // Multiplication by rot = ∓i is a swap and sign flip, not a full multiply.
+        let rotated = Complex::new(-sign * diff.im, sign * diff.re);
+        data[k] = even0 + sum;
+        data[k + half] = even0 - sum;
+        data[k + quarter] = even1 + rotated;
+        data[k + quarter + half] = even1 - rotated;
+    }
+}
+
+/// Direct `O(n²)` DFT used as the small-size and non-multiple-of-four fallback.
+fn direct_dft<T>(
+    data: &mut [Complex<T>],
+    scratch: &mut [Complex<T>],
+    twiddles: &[Complex<T>],
+    full_n: usize,
+)
+where
+    T: Float,
+{
+    let n = data.len();
+    let scale = full_n / n;
+    for k in 0..n {
+        let mut sum = Complex::new(T::zero(), T::zero());
+        for m in 0..n {
+            sum = sum + data[m] * twiddles[(k * m * scale) % full_n];
+        }
+        scratch[k] = sum;
+    }
+    data.copy_from_slice(&scratch[..n]);
+}
@@ -0,0 +1,163 @@
+#![feature(generic_const_exprs)]
+
+extern crate dft;
+extern crate num_complex;
+
+use core::mem::MaybeUninit;
+use std::f64::consts::PI;
+
+use dft::{
+    bluestein, circular_convolve, convolve, convolve_real, transform, transform_in_ring,
+    transform_split, c64, Fp, Operation, Plan, SplitPlan,
+};
+
+const P: u64 = 998244353;
+
+fn naive(data: &[c64], operation: Operation) -> Vec<c64> {
+    let n = data.len();
+    let sign = if let Operation::Forward = operation { -1.0 } else { 1.0 };
+    (0..n)
+        .map(|k| {
+            let mut sum = c64::new(0.0, 0.0);
+            for (m, &value) in data.iter().enumerate() {
+                let angle = sign * 2.0 * PI * (k * m) as f64 / n as f64;
+                sum += value * c64::new(angle.cos(), angle.sin());
+            }
+            sum
+        })
+        .collect()
+}
+
+fn close(left: &[c64], right: &[c64]) {
+    assert_eq!(left.len(), right.len());
+    for (a, b) in left.iter().zip(right) {
+        assert!((a.re - b.re).abs() < 1e-9, "{} vs {}", a, b);
+        assert!((a.im - b.im).abs() < 1e-9, "{} vs {}", a, b);
+    }
+}
+
+#[test]
+fn mixed_radix_forward() {
+    let mut data: [c64; 6] = core::array::from_fn(|i| c64::new(i as f64, (2 * i) as f64));
+    let reference = naive(&data, Operation::Forward);
+    let mut factors = MaybeUninit::uninit();
+    transform(&mut data, &Plan::new(Operation::Forward, &mut factors));
+    close(&data, &reference);
+}
+
+#[test]
+fn mixed_radix_round_trip() {
+    let original: [c64; 12] = core::array::from_fn(|i| c64::new((i as f64).sin(), i as f64));
+    let mut data = original;
+    let mut forward = MaybeUninit::uninit();
+    transform(&mut data, &Plan::new(Operation::Forward, &mut forward));
+    let mut inverse = MaybeUninit::uninit();
+    transform(&mut data, &Plan::new(Operation::Inverse, &mut inverse));
+    close(&data, &original);
+}
+
+#[test]
+fn bluestein_forward_prime() {
+    let mut data: [c64; 7] = core::array::from_fn(|i| c64::new(i as f64, 1.0));
+    let reference = naive(&data, Operation::Forward);
+    bluestein(&mut data, Operation::Forward);
+    close(&data, &reference);
+}
+
+#[test]
+fn bluestein_round_trip() {
+    let original: [c64; 5] = core::array::from_fn(|i| c64::new((i + 1) as f64, -(i as f64)));
+    let mut data = original;
+    bluestein(&mut data, Operation::Forward);
+    bluestein(&mut data, Operation::Inverse);
+    close(&data, &original);
+}
+
+#[test]
+fn ntt_round_trip() {
+    const N: usize = 8;
+    let original: [Fp<P>; N] = core::array::from_fn(|i| Fp::new((i as u64 + 1) * 3));
+    let mut data = original;
+    transform_in_ring(&mut data, Operation::Forward);
+    transform_in_ring(&mut data, Operation::Inverse);
+    assert_eq!(data, original);
+}
+
+#[test]
+fn ntt_convolution_matches_schoolbook() {
+    const N: usize = 8;
+    let a = [1u64, 2, 3, 4, 0, 0, 0, 0];
+    let b = [5u64, 6, 7, 8, 0, 0, 0, 0];
+    let mut schoolbook = [0u64; N];
+    for i in 0..4 {
+        for j in 0..4 {
+            schoolbook[i + j] += a[i] * b[j];
+        }
+    }
+
+    let mut fa: [Fp<P>; N] = core::array::from_fn(|i| Fp::new(a[i]));
+    let mut fb: [Fp<P>; N] = core::array::from_fn(|i| Fp::new(b[i]));
+    transform_in_ring(&mut fa, Operation::Forward);
+    transform_in_ring(&mut fb, Operation::Forward);
+    let mut product: [Fp<P>; N] =
+        core::array::from_fn(|i| Fp::new((fa[i].0 as u128 * fb[i].0 as u128 % P as u128) as u64));
+    transform_in_ring(&mut product, Operation::Inverse);
+    let expected: [Fp<P>; N] = core::array::from_fn(|i| Fp::new(schoolbook[i]));
+    assert_eq!(product, expected);
+}
+
+#[test]
+fn convolve_matches_direct() {
+    let a = [c64::new(1.0, 0.0), c64::new(2.0, 0.0), c64::new(3.0, 0.0)];
+    let b = [c64::new(4.0, 0.0), c64::new(5.0, 0.0)];
+    let result = convolve(&a, &b);
+    let expected = [
+        c64::new(4.0, 0.0),
+        c64::new(13.0, 0.0),
+        c64::new(22.0, 0.0),
+        c64::new(15.0, 0.0),
+    ];
+    close(&result, &expected);
+}
+
+#[test]
+fn convolve_real_multiplies_polynomials() {
+    // (1 + 2x)(3 + 4x + 5x²) = 3 + 10x + 13x² + 10x³
+    let result = convolve_real(&[1.0, 2.0], &[3.0, 4.0, 5.0]);
+    let expected = [3.0, 10.0, 13.0, 10.0];
+    for (a, b) in result.iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn circular_convolve_wraps() {
+    let a = [c64::new(1.0, 0.0), c64::new(2.0, 0.0)];
+    let b = [c64::new(3.0, 0.0), c64::new(4.0, 0.0)];
+    let result = circular_convolve(&a, &b);
+    // Circular: [1·3 + 2·4, 1·4 + 2·3] = [11, 10].
+    close(&result, &[c64::new(11.0, 0.0), c64::new(10.0, 0.0)]);
+}
+
+#[test]
+fn split_radix_matches_naive() {
+    let mut data: [c64; 16] = core::array::from_fn(|i| c64::new(i as f64, (16 - i) as f64));
+    let reference = naive(&data, Operation::Forward);
+    let mut twiddles = MaybeUninit::uninit();
+    let mut triples = MaybeUninit::uninit();
+    transform_split(&mut data, &SplitPlan::new(Operation::Forward, &mut twiddles, &mut triples));
+    close(&data, &reference);
+}
+
+#[test]
+fn split_radix_round_trip() {
+    let original: [c64; 32] = core::array::from_fn(|i| c64::new((i as f64).cos(), i as f64));
+    let mut data = original;
+    let mut twiddles = MaybeUninit::uninit();
+    let mut triples = MaybeUninit::uninit();
+    transform_split(&mut data, &SplitPlan::new(Operation::Forward, &mut twiddles, &mut triples));
+    let mut twiddles = MaybeUninit::uninit();
+    let mut triples = MaybeUninit::uninit();
+    transform_split(&mut data, &SplitPlan::new(Operation::Inverse, &mut twiddles, &mut triples));
+    close(&data, &original);
+}